@@ -11,14 +11,18 @@ use crate::config::{Config, TEMPLATE_DIR};
 use crate::error::{Error, Result};
 
 use beef::lean::Cow;
-use chrono::{DateTime as CDateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, Timelike};
+use chrono::{
+    DateTime as CDateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+    Utc,
+};
 use ramhorns::encoding::Encoder;
 use ramhorns::traits::ContentSequence;
 use ramhorns::{Content, Ramhorns, Section, Template};
 use serde::de::{self, Deserialize, Deserializer, Visitor};
 
+use std::cmp::Ordering;
 use std::collections::HashSet;
-use std::fmt;
+use std::fmt::{self, Write as _};
 use std::path::{is_separator, Path, PathBuf};
 use std::time::SystemTime;
 
@@ -32,9 +36,42 @@ pub struct Templates {
     theme: Option<Ramhorns>,
 }
 
-/// A wrapper around the `choron::NaiveDateTime`, used for rendering of dates.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct DateTime(NaiveDateTime);
+/// A wrapper around the `chrono::NaiveDateTime`, used for rendering of dates.
+///
+/// The offset the date was originally written in, if any, is kept alongside it so that
+/// feeds can emit real offset-aware timestamps instead of silently normalizing authors'
+/// local times to naive UTC. A missing offset is treated as UTC.
+#[derive(Clone, Copy, Debug)]
+pub struct DateTime {
+    naive: NaiveDateTime,
+    offset: Option<FixedOffset>,
+}
+
+// `FixedOffset` implements neither `PartialOrd` nor `Ord`, and comparing `naive`/`offset`
+// field-wise would sort by wall-clock time rather than by the instant it refers to (a
+// post timestamped `2024-01-01T23:00-05:00` would then sort before one timestamped
+// `2024-01-02T02:00+00:00`, even though the former is later in absolute time), so
+// equality and ordering are
+// defined by hand on the absolute instant instead.
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
 
 /// A wrapper around a `str` representing path, used to derive `Content` implementation
 /// that acts like an iterator over the path segmets.
@@ -56,6 +93,8 @@ struct Segment<'a>(
 #[derive(Clone, serde::Deserialize)]
 #[serde(untagged)]
 pub(crate) enum Any<'a> {
+    Bool(bool),
+    Integer(i64),
     Number(f64),
     #[serde(borrow)]
     String(Cow<'a, str>),
@@ -176,6 +215,8 @@ impl<'a> Content for Any<'a> {
     #[inline]
     fn is_truthy(&self) -> bool {
         match self {
+            Any::Bool(b) => *b,
+            Any::Integer(n) => *n != 0,
             Any::List(vec) => !vec.is_empty(),
             Any::Map(map) => !map.is_empty(),
             _ => false,
@@ -185,6 +226,8 @@ impl<'a> Content for Any<'a> {
     #[inline]
     fn render_escaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
         match self {
+            Any::Bool(b) => b.render_escaped(encoder),
+            Any::Integer(n) => n.render_escaped(encoder),
             Any::Number(n) => n.render_escaped(encoder),
             Any::String(s) => s.render_escaped(encoder),
             Any::DateTime(dt) => dt.render_escaped(encoder),
@@ -196,6 +239,8 @@ impl<'a> Content for Any<'a> {
     #[inline]
     fn render_unescaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
         match self {
+            Any::Bool(b) => b.render_unescaped(encoder),
+            Any::Integer(n) => n.render_unescaped(encoder),
             Any::Number(n) => n.render_unescaped(encoder),
             Any::String(s) => s.render_unescaped(encoder),
             Any::DateTime(dt) => dt.render_unescaped(encoder),
@@ -280,6 +325,30 @@ impl DateTime {
     pub fn now() -> Self {
         SystemTime::now().into()
     }
+
+    /// The offset this date was originally written in, defaulting to UTC.
+    fn offset(&self) -> FixedOffset {
+        self.offset.unwrap_or_else(|| FixedOffset::east_opt(0).unwrap())
+    }
+
+    /// This date and time together with its offset, for RFC 3339 / RFC 2822 rendering.
+    fn with_offset(&self) -> CDateTime<FixedOffset> {
+        self.offset().from_local_datetime(&self.naive).unwrap()
+    }
+
+    /// The absolute instant this date refers to, used for equality and ordering.
+    fn instant(&self) -> CDateTime<Utc> {
+        self.with_offset().with_timezone(&Utc)
+    }
+}
+
+/// Format `dt` with a chrono format string, returning `None` instead of panicking when
+/// `fmt_str` is malformed (`DelayedFormat`'s `Display` impl returns `Err` in that case,
+/// and `ToString::to_string` would otherwise unwrap that error).
+fn try_format(dt: &CDateTime<FixedOffset>, fmt_str: &str) -> Option<String> {
+    let mut buf = String::new();
+    write!(buf, "{}", dt.format(fmt_str)).ok()?;
+    Some(buf)
 }
 
 impl Content for DateTime {
@@ -297,8 +366,16 @@ impl Content for DateTime {
     where
         E: Encoder,
     {
-        if name.len() != 1 {
-            return Ok(false);
+        if name.len() > 1 {
+            let formatted = match name {
+                "rfc3339" => Some(self.with_offset().to_rfc3339()),
+                "rfc2822" => Some(self.with_offset().to_rfc2822()),
+                _ => try_format(&self.with_offset(), name),
+            };
+            return match formatted {
+                Some(s) => enc.write_unescaped(&s).map(|_| true),
+                None => Ok(false),
+            };
         }
 
         const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
@@ -314,29 +391,33 @@ impl Content for DateTime {
         ];
 
         match name.bytes().next().unwrap_or(0) {
-            b'y' => self.0.year().render_unescaped(enc).map(|_| true),
+            b'y' => self.naive.year().render_unescaped(enc).map(|_| true),
             b'm' => enc
-                .write_unescaped(NUMS[self.0.month() as usize])
+                .write_unescaped(NUMS[self.naive.month() as usize])
                 .map(|_| true),
             b'd' => enc
-                .write_unescaped(NUMS[self.0.day() as usize])
+                .write_unescaped(NUMS[self.naive.day() as usize])
                 .map(|_| true),
-            b'e' => self.0.day().render_unescaped(enc).map(|_| true),
+            b'e' => self.naive.day().render_unescaped(enc).map(|_| true),
             b'H' => enc
-                .write_unescaped(NUMS[self.0.hour() as usize])
+                .write_unescaped(NUMS[self.naive.hour() as usize])
                 .map(|_| true),
             b'M' => enc
-                .write_unescaped(NUMS[self.0.minute() as usize])
+                .write_unescaped(NUMS[self.naive.minute() as usize])
                 .map(|_| true),
             b'S' => enc
-                .write_unescaped(NUMS[self.0.second() as usize])
+                .write_unescaped(NUMS[self.naive.second() as usize])
                 .map(|_| true),
             b'a' => enc
-                .write_unescaped(WEEKDAYS[self.0.weekday().num_days_from_sunday() as usize])
+                .write_unescaped(WEEKDAYS[self.naive.weekday().num_days_from_sunday() as usize])
                 .map(|_| true),
             b'b' => enc
-                .write_unescaped(MONTHS[self.0.month0() as usize])
+                .write_unescaped(MONTHS[self.naive.month0() as usize])
                 .map(|_| true),
+            b'z' => match try_format(&self.with_offset(), "%z") {
+                Some(s) => enc.write_unescaped(&s).map(|_| true),
+                None => Ok(false),
+            },
             _ => Ok(false),
         }
     }
@@ -411,10 +492,25 @@ impl<'de> Deserialize<'de> for DateTime {
                 }
                 let v: &str = visitor.next_value()?;
                 v.parse::<NaiveDateTime>()
-                    .or_else(|_| v.parse::<NaiveDate>().map(|d| d.and_hms(0, 0, 0)))
-                    .or_else(|_| NaiveDateTime::parse_from_str(v, "%F %T%.f"))
-                    .or_else(|_| v.parse::<CDateTime<FixedOffset>>().map(|d| d.naive_utc()))
-                    .map(DateTime)
+                    .map(|naive| DateTime { naive, offset: None })
+                    .or_else(|_| {
+                        v.parse::<NaiveDate>().map(|d| DateTime {
+                            naive: d.and_hms_opt(0, 0, 0).unwrap(),
+                            offset: None,
+                        })
+                    })
+                    .or_else(|_| {
+                        NaiveDateTime::parse_from_str(v, "%F %T%.f").map(|naive| DateTime {
+                            naive,
+                            offset: None,
+                        })
+                    })
+                    .or_else(|_| {
+                        v.parse::<CDateTime<FixedOffset>>().map(|d| DateTime {
+                            naive: d.naive_local(),
+                            offset: Some(*d.offset()),
+                        })
+                    })
                     .map_err(|_| {
                         de::Error::custom(format!("unable to parse date and time from {}", v))
                     })
@@ -429,6 +525,9 @@ impl<'de> Deserialize<'de> for DateTime {
 impl From<SystemTime> for DateTime {
     fn from(st: SystemTime) -> Self {
         let time: chrono::DateTime<chrono::Utc> = st.into();
-        DateTime(time.naive_utc())
+        DateTime {
+            naive: time.naive_utc(),
+            offset: None,
+        }
     }
 }